@@ -0,0 +1,100 @@
+//! The derive macro for [`LifetimeCast`](../lifetime_gats/trait.LifetimeCast.html).
+//!
+//! Hand-writing a `LifetimeCast` impl is just three `mem::transmute` calls
+//! wearing different hats, which makes it easy to paper over a type that
+//! isn't actually safe to transmute (anything invariant or contravariant in
+//! its lifetime parameter). `#[derive(LifetimeCast)]` generates the same
+//! three methods and, crucially, a hidden covariance check so that unsound
+//! inputs fail to compile instead of compiling into UB.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, DeriveInput, GenericParam, Lifetime};
+
+/// Derives [`LifetimeCast`] for a struct with exactly one lifetime parameter
+/// and no other generics that would make the transmute unsound.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(LifetimeCast)]
+/// struct SpecialRef<'a>(&'a u32);
+/// ```
+///
+/// expands to an `unsafe impl LifetimeCast<'b> for SpecialRef<'a>` whose
+/// three methods are `mem::transmute`, plus a hidden function that asserts
+/// `SpecialRef<'a>` is covariant over `'a` (the derive fails to compile
+/// otherwise).
+#[proc_macro_derive(LifetimeCast)]
+pub fn derive_lifetime_cast(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = input.ident;
+
+    let lifetimes: Vec<_> = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Lifetime(lt) => Some(lt.lifetime.clone()),
+            _ => None,
+        })
+        .collect();
+    let ty_params: Vec<_> = input
+        .generics
+        .params
+        .iter()
+        .filter(|p| matches!(p, GenericParam::Type(_) | GenericParam::Const(_)))
+        .collect();
+
+    if lifetimes.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            &name,
+            "LifetimeCast can only be derived for types with exactly one lifetime parameter",
+        ));
+    }
+    if !ty_params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &name,
+            "LifetimeCast cannot be derived for types with generic type parameters, \
+             as the transmute this derive generates would not be sound",
+        ));
+    }
+
+    let a = &lifetimes[0];
+    let b = Lifetime::new("'__lifetime_cast_b", Span::call_site());
+    let assert_fn = format_ident!("__assert_covariant_{}", name);
+
+    Ok(quote! {
+        unsafe impl<#a, #b> ::lifetime_gats::LifetimeCast<#b> for #name<#a> {
+            type Target = #name<#b>;
+
+            unsafe fn cast(self) -> Self::Target {
+                ::std::mem::transmute(self)
+            }
+            unsafe fn cast_reference(&self) -> &Self::Target {
+                ::std::mem::transmute(self)
+            }
+            unsafe fn cast_reference_mut(&mut self) -> &mut Self::Target {
+                ::std::mem::transmute(self)
+            }
+        }
+
+        // If `#name` is invariant or contravariant over its lifetime
+        // parameter, the `transmute`s above would be unsound; this function
+        // only type-checks when `#name` is covariant, turning that footgun
+        // into a compile error.
+        #[allow(non_snake_case, dead_code)]
+        fn #assert_fn<#a: #b, #b>(x: #name<#a>) -> #name<#b> {
+            x
+        }
+    })
+}
@@ -0,0 +1,22 @@
+//! Positive coverage for `#[derive(LifetimeCast)]`: it should accept a
+//! covariant single-lifetime struct and produce an impl that round-trips
+//! through `Reference::map` like a hand-written one would.
+
+use lifetime_gats::{LifetimeCast, Reference};
+
+#[derive(LifetimeCast)]
+struct Num<'a>(&'a u32);
+
+#[test]
+fn round_trips_through_reference_map() {
+    let value = 10u32;
+    let r = Reference::new(Num(&value));
+    let projected = r.map(|n| n.0);
+    assert_eq!(**projected.get(), 10);
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
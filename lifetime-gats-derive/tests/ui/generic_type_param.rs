@@ -0,0 +1,9 @@
+// `#[derive(LifetimeCast)]` rejects generic type parameters, since the
+// generated transmute would not be sound for an arbitrary `T`.
+
+use lifetime_gats::LifetimeCast;
+
+#[derive(LifetimeCast)]
+struct Generic<'a, T>(&'a T);
+
+fn main() {}
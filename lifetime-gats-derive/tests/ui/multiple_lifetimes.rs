@@ -0,0 +1,8 @@
+// `#[derive(LifetimeCast)]` only supports exactly one lifetime parameter.
+
+use lifetime_gats::LifetimeCast;
+
+#[derive(LifetimeCast)]
+struct TwoLifetimes<'a, 'b>(&'a u32, &'b u32);
+
+fn main() {}
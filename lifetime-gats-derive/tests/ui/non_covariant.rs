@@ -0,0 +1,11 @@
+// `#[derive(LifetimeCast)]` emits a hidden covariance assertion, so a type
+// that is invariant over its lifetime parameter (here, via `Cell`) fails to
+// compile instead of getting an unsound `transmute`-based impl.
+
+use lifetime_gats::LifetimeCast;
+use std::cell::Cell;
+
+#[derive(LifetimeCast)]
+struct Invariant<'a>(Cell<&'a u32>);
+
+fn main() {}
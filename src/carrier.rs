@@ -0,0 +1,81 @@
+use crate::LifetimeCast;
+
+/// A container that bundles an owned value with a borrowed view into it.
+///
+/// `Reference`/`ReferenceMut` can move a lifetime out of a type, but they
+/// can't keep the owner of that borrow alive alongside it, so they can
+/// never be the return type of a function that creates its own owned data.
+/// `Carrier<O, F>` fixes that: `O` is an owned backing value (e.g.
+/// `Box<Vec<u32>>`) and `F` is the `'static`-cast form of a [`LifetimeCast`]
+/// type describing a borrow into `O` (e.g. `SpecialRef<'static>`). Like
+/// `Reference`, the borrow's lifetime is erased to `'static` for storage and
+/// only handed back out at its real lifetime, here tied to `&self` instead
+/// of to an ambient type parameter.
+pub struct Carrier<O, F> {
+    // Declared before `owner` so it's dropped first: `payload` borrows from
+    // `owner`, and must not outlive it.
+    payload: F,
+    // Never read directly; kept only to extend its lifetime to drop after
+    // `payload`.
+    #[allow(dead_code)]
+    owner: O,
+}
+
+impl<O, F> Carrier<O, F> {
+    /// Builds a `Carrier` by attaching a borrowed view onto `owner`.
+    ///
+    /// `f` is handed a reference to `owner` at some lifetime `'r` and must
+    /// return the real, `'r`-borrowed view of `F` (`<F as
+    /// LifetimeCast<'r>>::Target`) — e.g. `f` can actually borrow out of the
+    /// `&'r O` it's given, unlike a plain `impl FnOnce(&O) -> B` would allow,
+    /// since `B` there couldn't depend on `'r`. That view is then cast to
+    /// `F` for storage, exactly as [`Reference::new`] casts its argument.
+    ///
+    /// # Safety
+    ///
+    /// `owner` must live behind a stable address (e.g. a `Box` or `Pin`),
+    /// so that moving the returned `Carrier` around cannot invalidate the
+    /// borrow `f` takes out of it. `f` must not leak that borrow anywhere
+    /// outside of the value it returns.
+    ///
+    /// [`Reference::new`]: crate::Reference::new
+    pub unsafe fn attach(
+        owner: O,
+        f: impl for<'r> FnOnce(&'r O) -> <F as LifetimeCast<'r>>::Target,
+    ) -> Carrier<O, F>
+    where
+        F: for<'r> LifetimeCast<'r>,
+        for<'r> <F as LifetimeCast<'r>>::Target: LifetimeCast<'static, Target = F>,
+    {
+        let payload = unsafe { f(&owner).cast() };
+        Carrier { payload, owner }
+    }
+
+    /// Re-derives the borrowed view at its true lifetime, tied to `&self`.
+    pub fn get<'s>(&'s self) -> &'s F::Target
+    where
+        F: LifetimeCast<'s>,
+    {
+        unsafe { self.payload.cast_reference() }
+    }
+
+    /// Re-derives the borrowed view at its true lifetime, tied to `&mut self`.
+    pub fn get_mut<'s>(&'s mut self) -> &'s mut F::Target
+    where
+        F: LifetimeCast<'s>,
+    {
+        unsafe { self.payload.cast_reference_mut() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_borrows_from_owner() {
+        let carrier: Carrier<Box<u32>, &'static u32> =
+            unsafe { Carrier::attach(Box::new(10), |owner: &Box<u32>| &**owner) };
+        assert_eq!(**carrier.get(), 10);
+    }
+}
@@ -1,6 +1,13 @@
 use std::marker::PhantomData;
 use std::{mem, ops};
 
+mod carrier;
+
+pub use carrier::Carrier;
+
+#[cfg(feature = "derive")]
+pub use lifetime_gats_derive::LifetimeCast;
+
 /// A type that abstracts over `&'a T` and `T<'a>`, moving the lifetime outside
 /// of the type.
 ///
@@ -22,6 +29,33 @@ pub struct Reference<'a, T: 'a>(T, PhantomData<&'a mut T>);
 /// See [`Reference`] for more details.
 pub struct ReferenceMut<'a, T: 'a>(T, PhantomData<&'a mut T>);
 
+// `Reference`/`ReferenceMut` store their payload already cast to `'static`,
+// so a derived auto trait impl would be computed from that erased type
+// rather than the real borrowed type (`T` and `PhantomData<&'a mut T>`,
+// both `'static`-typed). Key the impls off `LifetimeCast::Target`, the type
+// that's actually being borrowed, instead.
+unsafe impl<'a, T: LifetimeCast<'a>> Send for Reference<'a, T>
+where
+    T::Target: Send,
+{
+}
+unsafe impl<'a, T: LifetimeCast<'a>> Sync for Reference<'a, T>
+where
+    T::Target: Sync,
+{
+}
+
+unsafe impl<'a, T: LifetimeCast<'a>> Send for ReferenceMut<'a, T>
+where
+    T::Target: Send,
+{
+}
+unsafe impl<'a, T: LifetimeCast<'a>> Sync for ReferenceMut<'a, T>
+where
+    T::Target: Sync,
+{
+}
+
 /// A trait implemented by types that can be stored inside [`Reference`] and [`ReferenceMut`].
 ///
 /// `Target` must be the same type as `Self` excluding a lifetime parameter.
@@ -29,9 +63,12 @@ pub struct ReferenceMut<'a, T: 'a>(T, PhantomData<&'a mut T>);
 /// # Example
 ///
 /// ```rust
+/// use lifetime_gats::LifetimeCast;
+/// use std::mem;
+///
 /// struct SpecialRef<'a>(&'a u32);
 ///
-/// unsafe impl LifetimeCast<'b> for SpecialRef<'a> {
+/// unsafe impl<'a, 'b> LifetimeCast<'b> for SpecialRef<'a> {
 ///     type Target = SpecialRef<'b>;
 ///     unsafe fn cast(self) -> Self::Target {
 ///         mem::transmute(self)
@@ -44,11 +81,27 @@ pub struct ReferenceMut<'a, T: 'a>(T, PhantomData<&'a mut T>);
 ///     }
 /// }
 /// ```
+///
+/// Writing these three methods by hand is just `mem::transmute` in three
+/// costumes, and it's easy to write them for a type where that's not
+/// actually sound. The `lifetime-gats-derive` crate provides
+/// `#[derive(LifetimeCast)]`, which generates the impl above for you and
+/// additionally emits a hidden covariance assertion, so a type that is
+/// invariant or contravariant over its lifetime parameter fails to compile
+/// instead of silently producing undefined behavior.
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Self` and `Target` differ only in the
+/// lifetime `'a`, and that `Self` is covariant over that lifetime, so that
+/// the `cast*` methods below are sound to implement as a `mem::transmute`.
 pub unsafe trait LifetimeCast<'a>: Sized {
     type Target: 'a;
 
     /// Casts away the lifetime in `Self` to `'a`.
     ///
+    /// # Safety
+    ///
     /// Callers must guarantee that this is safe by providing some external
     /// guarantee which restricts the lifetime.
     unsafe fn cast(self) -> Self::Target;
@@ -57,15 +110,23 @@ pub unsafe trait LifetimeCast<'a>: Sized {
     ///
     /// This is intended for the use case where we need to map `&T<'a>` to `&T<'b>`:
     /// calling `cast` would not allow us to cast the inner type, while this function does.
+    ///
+    /// # Safety
+    ///
+    /// See `cast`.
     unsafe fn cast_reference(&self) -> &Self::Target;
 
     /// Casts away the lifetime of `Self` to `'a`.
     ///
     /// See `cast_reference` for details.
+    ///
+    /// # Safety
+    ///
+    /// See `cast`.
     unsafe fn cast_reference_mut(&mut self) -> &mut Self::Target;
 }
 
-impl<T> Reference<'a, T> {
+impl<'a, T> Reference<'a, T> {
     /// Create a `Reference` type from some type.
     ///
     /// The returned type (`T::Target`) will be `'static`, which will look odd, as
@@ -82,6 +143,71 @@ impl<T> Reference<'a, T> {
     }
 }
 
+impl<'a, T> Reference<'a, T> {
+    /// Shortens the erased lifetime `'a` to any outlived `'b`.
+    ///
+    /// `Reference` is covariant over `'a` (see the type-level docs), but
+    /// that subtyping is implicit and doesn't kick in through generic code,
+    /// e.g. when unifying two `Reference`s stored in the same struct. This
+    /// gives callers an explicit, discoverable way to do the same thing.
+    ///
+    /// This is a safe no-op: the payload in `self.0` is already
+    /// `'static`-typed, and `'a` lives only in the `PhantomData` marker, so
+    /// re-tagging it with a shorter `'b` changes nothing about what's
+    /// actually stored.
+    pub fn reborrow<'b>(self) -> Reference<'b, T>
+    where
+        'a: 'b,
+    {
+        Reference(self.0, PhantomData)
+    }
+}
+
+impl<'a, T: LifetimeCast<'a>> Reference<'a, T> {
+    /// Borrows the referent at its real lifetime `'a`.
+    ///
+    /// Unlike the `Deref` impl, this doesn't require `T: Copy`, so it's the
+    /// only way to read through a `Reference` whose payload isn't `Copy` —
+    /// e.g. a struct deriving `LifetimeCast` but not `Copy`.
+    pub fn get(&self) -> &T::Target {
+        // Safe: casting to 'a (the actual lifetime) is always safe, see
+        // `Deref`'s impl below.
+        unsafe { self.0.cast_reference() }
+    }
+
+    /// Projects into a sub-borrow of the referent, keeping the result inside
+    /// a lifetime-erased `Reference` instead of falling back to a plain
+    /// `&'a U`.
+    ///
+    /// `f` runs against the real `'a` view of the payload (obtained via
+    /// [`LifetimeCast::cast_reference`]), and the `&U` it returns is
+    /// re-wrapped through the `LifetimeCast` impl for `&U` exactly like
+    /// [`Reference::new`] would.
+    pub fn map<U: 'static>(self, f: impl FnOnce(&T::Target) -> &U) -> Reference<'a, &'static U> {
+        unsafe {
+            // Safe: `cast_reference` views `self.0` at its real lifetime
+            // `'a`, so the `&U` that `f` derives from it is valid for `'a`
+            // even though the compiler only sees it tied to the temporary
+            // `&self.0` passed into `cast_reference`.
+            let real = self.0.cast_reference();
+            let projected: &'a U = mem::transmute(f(real));
+            Reference::new(projected)
+        }
+    }
+
+    /// Like [`Reference::map`], but for projections that might not apply.
+    pub fn try_map<U: 'static>(
+        self,
+        f: impl FnOnce(&T::Target) -> Option<&U>,
+    ) -> Option<Reference<'a, &'static U>> {
+        unsafe {
+            let real = self.0.cast_reference();
+            let projected: Option<&'a U> = f(real).map(|u| mem::transmute(u));
+            projected.map(Reference::new)
+        }
+    }
+}
+
 impl<'a, T> ReferenceMut<'a, T> {
     /// Create a `ReferenceMut` type from some type.
     ///
@@ -94,7 +220,64 @@ impl<'a, T> ReferenceMut<'a, T> {
     }
 }
 
-impl<T: LifetimeCast<'a> + Copy> ops::Deref for Reference<'a, T> {
+impl<'a, T> ReferenceMut<'a, T> {
+    /// Shortens the erased lifetime `'a` to any outlived `'b`.
+    ///
+    /// See [`Reference::reborrow`] for why this is a safe no-op.
+    pub fn reborrow<'b>(self) -> ReferenceMut<'b, T>
+    where
+        'a: 'b,
+    {
+        ReferenceMut(self.0, PhantomData)
+    }
+}
+
+impl<'a, T: LifetimeCast<'a>> ReferenceMut<'a, T> {
+    /// Borrows the referent at its real lifetime `'a`.
+    ///
+    /// See [`Reference::get`] for why this exists alongside `Deref`.
+    pub fn get(&self) -> &T::Target {
+        unsafe { self.0.cast_reference() }
+    }
+
+    /// Mutably borrows the referent at its real lifetime `'a`.
+    ///
+    /// Unlike the `DerefMut` impl, this doesn't require `T: Copy`, so it's
+    /// the only way to write through a `ReferenceMut` whose payload isn't
+    /// `Copy` — e.g. the result of [`ReferenceMut::map_mut`].
+    pub fn get_mut(&mut self) -> &mut T::Target {
+        unsafe { self.0.cast_reference_mut() }
+    }
+
+    /// Projects into a sub-borrow of the referent, keeping the result inside
+    /// a lifetime-erased `ReferenceMut`.
+    ///
+    /// See [`Reference::map`] for how the projection is carried out.
+    pub fn map_mut<U: 'static>(
+        mut self,
+        f: impl FnOnce(&mut T::Target) -> &mut U,
+    ) -> ReferenceMut<'a, &'static mut U> {
+        unsafe {
+            let real = self.0.cast_reference_mut();
+            let projected: &'a mut U = mem::transmute(f(real));
+            ReferenceMut::new(projected)
+        }
+    }
+
+    /// Like [`ReferenceMut::map_mut`], but for projections that might not apply.
+    pub fn try_map_mut<U: 'static>(
+        mut self,
+        f: impl FnOnce(&mut T::Target) -> Option<&mut U>,
+    ) -> Option<ReferenceMut<'a, &'static mut U>> {
+        unsafe {
+            let real = self.0.cast_reference_mut();
+            let projected: Option<&'a mut U> = f(real).map(|u| mem::transmute(u));
+            projected.map(ReferenceMut::new)
+        }
+    }
+}
+
+impl<'a, T: LifetimeCast<'a> + Copy> ops::Deref for Reference<'a, T> {
     type Target = T::Target;
     fn deref(&self) -> &Self::Target {
         // casting to 'a (the actual lifetime) is always safe
@@ -104,7 +287,7 @@ impl<T: LifetimeCast<'a> + Copy> ops::Deref for Reference<'a, T> {
     }
 }
 
-impl<T: LifetimeCast<'a> + Copy> ops::Deref for ReferenceMut<'a, T> {
+impl<'a, T: LifetimeCast<'a> + Copy> ops::Deref for ReferenceMut<'a, T> {
     type Target = T::Target;
     fn deref(&self) -> &Self::Target {
         // casting to 'a (the actual lifetime) is always safe
@@ -114,7 +297,7 @@ impl<T: LifetimeCast<'a> + Copy> ops::Deref for ReferenceMut<'a, T> {
     }
 }
 
-impl<T: LifetimeCast<'a> + Copy> ops::DerefMut for ReferenceMut<'a, T> {
+impl<'a, T: LifetimeCast<'a> + Copy> ops::DerefMut for ReferenceMut<'a, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // This overrides the fact that `self` is invariant here (behind &mut).
         // However, because conceptually we're returning the same type we were
@@ -125,7 +308,7 @@ impl<T: LifetimeCast<'a> + Copy> ops::DerefMut for ReferenceMut<'a, T> {
     }
 }
 
-unsafe impl<T: 'static> LifetimeCast<'b> for &'a T {
+unsafe impl<'b, T: 'static> LifetimeCast<'b> for &T {
     type Target = &'b T;
     unsafe fn cast(self) -> &'b T {
         mem::transmute(self)
@@ -138,7 +321,7 @@ unsafe impl<T: 'static> LifetimeCast<'b> for &'a T {
     }
 }
 
-unsafe impl<T: 'static> LifetimeCast<'b> for &'a mut T {
+unsafe impl<'b, T: 'static> LifetimeCast<'b> for &mut T {
     type Target = &'b mut T;
     unsafe fn cast(self) -> Self::Target {
         mem::transmute(self)
@@ -150,3 +333,81 @@ unsafe impl<T: 'static> LifetimeCast<'b> for &'a mut T {
         mem::transmute(self)
     }
 }
+
+/// Marker for types that contain no lifetime parameters, borrowed from
+/// castaway's trait of the same name.
+///
+/// Every [`LifetimeCast`] impl in this crate, and [`Reference::new`] itself,
+/// rests on `mem::transmute` plus a `T: 'static` bound, which blocks any
+/// payload that happens to borrow from something else and keeps the whole
+/// API `unsafe`. `LifetimeFree` carves out the common case: if `U` has no
+/// lifetime parameter at all, there is nothing inside it that could vary
+/// with the lifetime being cast away, so widening `&'a U` to `&'static U`
+/// is sound regardless of what `U` actually contains.
+///
+/// # Safety
+///
+/// Implementors must not have any lifetime parameters, nor contain any
+/// field whose type does. [`Reference::new_safe`] and
+/// [`ReferenceMut::new_safe`] rely on this to justify the `transmute` they
+/// perform themselves, without going through a [`LifetimeCast`] impl.
+pub unsafe trait LifetimeFree {}
+
+macro_rules! lifetime_free_primitive {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl LifetimeFree for $ty {})*
+    };
+}
+
+lifetime_free_primitive!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    String,
+);
+
+unsafe impl<T: LifetimeFree> LifetimeFree for Option<T> {}
+unsafe impl<T: LifetimeFree> LifetimeFree for Box<T> {}
+unsafe impl<T: LifetimeFree> LifetimeFree for Vec<T> {}
+
+impl<'a, U: LifetimeFree + 'static> Reference<'a, &'static U> {
+    /// Safely builds a `Reference` to a reference of a `LifetimeFree` type.
+    ///
+    /// Unlike [`Reference::new`], this doesn't go through a [`LifetimeCast`]
+    /// impl: `U: LifetimeFree` is the proof that widening `&'a U` to
+    /// `&'static U` for storage can't be wrong, so the crate discharges that
+    /// proof itself and the call site needs no `unsafe`.
+    pub fn new_safe(r: &'a U) -> Reference<'a, &'static U> {
+        // SAFETY: sound because of the `LifetimeFree` bound on `U`, see
+        // `LifetimeFree`'s docs.
+        Reference(unsafe { mem::transmute::<&'a U, &'static U>(r) }, PhantomData)
+    }
+}
+
+impl<'a, U: LifetimeFree + 'static> ReferenceMut<'a, &'static mut U> {
+    /// Safely builds a `ReferenceMut` to a reference of a `LifetimeFree` type.
+    ///
+    /// See [`Reference::new_safe`] for why this doesn't need `unsafe`.
+    pub fn new_safe(r: &'a mut U) -> ReferenceMut<'a, &'static mut U> {
+        // SAFETY: sound because of the `LifetimeFree` bound on `U`, see
+        // `LifetimeFree`'s docs.
+        ReferenceMut(
+            unsafe { mem::transmute::<&'a mut U, &'static mut U>(r) },
+            PhantomData,
+        )
+    }
+}
@@ -0,0 +1,9 @@
+//! Compile-fail assertions for the `Send`/`Sync` impls on `Reference`, run
+//! via `trybuild` since these are properties of the type system, not
+//! something a runtime `#[test]` can observe.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,28 @@
+// A `Reference` over a non-`Sync` view must not itself be `Sync`.
+
+use lifetime_gats::{LifetimeCast, Reference};
+use std::cell::Cell;
+use std::mem;
+
+struct NotSync<'a>(&'a Cell<u32>);
+
+unsafe impl<'a, 'b> LifetimeCast<'b> for NotSync<'a> {
+    type Target = NotSync<'b>;
+    unsafe fn cast(self) -> Self::Target {
+        mem::transmute(self)
+    }
+    unsafe fn cast_reference(&self) -> &Self::Target {
+        mem::transmute(self)
+    }
+    unsafe fn cast_reference_mut(&mut self) -> &mut Self::Target {
+        mem::transmute(self)
+    }
+}
+
+fn assert_sync<T: Sync>(_: T) {}
+
+fn main() {
+    let cell = Cell::new(0u32);
+    let r: Reference<'_, NotSync<'_>> = Reference::new(NotSync(&cell));
+    assert_sync(r);
+}